@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Request as FetchRequest, RequestInit, Response as FetchResponse, Window, WorkerGlobalScope};
+
+use super::endpoints::Endpoint;
+
+const API_BASE: &str = "https://api.cloudflare.com/client/v4";
+
+/// Issues `request` via whichever global scope's `fetch` is available. A
+/// Worker runs in a `WorkerGlobalScope`, not a `Window` (there is no DOM),
+/// so that's tried first; falling back to `Window` keeps this usable
+/// outside a worker, e.g. from a browser page or extension. Any other global
+/// scope is rejected with an error.
+async fn fetch(request: &FetchRequest) -> Result<FetchResponse, JsValue> {
+    let global = js_sys::global();
+    let promise = if let Some(scope) = global.dyn_ref::<WorkerGlobalScope>() {
+        scope.fetch_with_request(request)
+    } else if let Some(window) = global.dyn_ref::<Window>() {
+        window.fetch_with_request(request)
+    } else {
+        return Err(JsValue::from_str("no global `WorkerGlobalScope` or `Window`"));
+    };
+    JsFuture::from(promise).await?.dyn_into()
+}
+
+/// Joins `params` into a leading-`?` query string, or an empty string if
+/// there are none.
+fn query_string(params: &[(&str, &str)]) -> String {
+    if params.is_empty() {
+        return String::new();
+    }
+    let pairs: Vec<String> = params.iter().map(|(key, value)| format!("{}={}", key, value)).collect();
+    format!("?{}", pairs.join("&"))
+}
+
+/// Credentials for authenticating against the v4 API: either a single API
+/// token, or a legacy key + email pair.
+pub enum Credentials {
+    Token(String),
+    Key { key: String, email: String },
+}
+
+/// The v4 API's standard response envelope.
+#[derive(Debug, Deserialize)]
+pub struct ApiResponse<T> {
+    pub result: Option<T>,
+    pub success: bool,
+    #[serde(default)]
+    pub errors: Vec<ApiError>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ApiError {
+    pub code: u32,
+    pub message: String,
+}
+
+/// A minimal async v4 API client, modelled after the established Rust v4
+/// wrapper.
+pub struct ApiClient {
+    credentials: Credentials,
+}
+
+impl ApiClient {
+    pub fn new(credentials: Credentials) -> ApiClient {
+        ApiClient { credentials }
+    }
+
+    pub(crate) fn auth_headers(&self) -> HashMap<&'static str, String> {
+        let mut headers = HashMap::new();
+        match &self.credentials {
+            Credentials::Token(token) => {
+                headers.insert("Authorization", format!("Bearer {}", token));
+            }
+            Credentials::Key { key, email } => {
+                headers.insert("X-Auth-Key", key.clone());
+                headers.insert("X-Auth-Email", email.clone());
+            }
+        }
+        headers
+    }
+
+    /// Executes `endpoint` against the v4 API and deserializes the
+    /// envelope into `ApiResponse<E::Result>`.
+    pub async fn send<E: Endpoint>(&self, endpoint: &E) -> Result<ApiResponse<E::Result>, JsValue> {
+        let url = format!("{}{}{}", API_BASE, endpoint.path(), query_string(endpoint.query()));
+
+        let init = RequestInit::new();
+        init.set_method(endpoint.method());
+        if let Some(body) = endpoint.body() {
+            init.set_body(&JsValue::from_str(&body));
+        }
+
+        let request = FetchRequest::new_with_str_and_init(&url, &init)?;
+        for (name, value) in self.auth_headers() {
+            request.headers().set(name, &value)?;
+        }
+        request.headers().set("Content-Type", "application/json")?;
+
+        let response = fetch(&request).await?;
+        let text = JsFuture::from(response.text()?).await?;
+        let text = text.as_string().ok_or_else(|| JsValue::from_str("response body was not text"))?;
+
+        serde_json::from_str(&text).map_err(|err| JsValue::from_str(&err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_string_is_empty_with_no_params() {
+        assert_eq!(query_string(&[]), "");
+    }
+
+    #[test]
+    fn query_string_joins_params_with_a_leading_question_mark() {
+        assert_eq!(query_string(&[("name", "example.com"), ("status", "active")]), "?name=example.com&status=active");
+    }
+}
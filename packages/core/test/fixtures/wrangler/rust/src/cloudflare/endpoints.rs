@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+/// A single Cloudflare v4 API endpoint: its HTTP method, path, and
+/// serializable body/query.
+pub trait Endpoint {
+    /// The shape of a successful `result` field for this endpoint.
+    type Result: for<'de> Deserialize<'de>;
+
+    fn method(&self) -> &'static str;
+    fn path(&self) -> String;
+
+    /// Query-string parameters to attach to the request URL, if any.
+    fn query(&self) -> &[(&str, &str)] {
+        &[]
+    }
+
+    /// A JSON-encoded request body, if any.
+    fn body(&self) -> Option<String> {
+        None
+    }
+}
+
+/// `GET /zones` — lists zones visible to the authenticated account.
+pub struct ListZones;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Zone {
+    pub id: String,
+    pub name: String,
+}
+
+impl Endpoint for ListZones {
+    type Result = Vec<Zone>;
+
+    fn method(&self) -> &'static str {
+        "GET"
+    }
+
+    fn path(&self) -> String {
+        "/zones".to_string()
+    }
+}
+
+/// `POST /zones/:zone_id/purge_cache` — purges everything, or just the
+/// given files, from a zone's cache. `files: None` means "purge
+/// everything"; `files: Some(vec![])` means "purge these zero files" (a
+/// no-op) and must NOT be widened into purging everything.
+pub struct PurgeCache {
+    pub zone_id: String,
+    pub files: Option<Vec<String>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+enum PurgeCacheBody<'a> {
+    Everything { purge_everything: bool },
+    Files { files: &'a [String] },
+}
+
+impl Endpoint for PurgeCache {
+    type Result = serde_json::Value;
+
+    fn method(&self) -> &'static str {
+        "POST"
+    }
+
+    fn path(&self) -> String {
+        format!("/zones/{}/purge_cache", self.zone_id)
+    }
+
+    fn body(&self) -> Option<String> {
+        let body = match &self.files {
+            None => PurgeCacheBody::Everything { purge_everything: true },
+            Some(files) => PurgeCacheBody::Files { files },
+        };
+        serde_json::to_string(&body).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn list_zones_path_and_method() {
+        assert_eq!(ListZones.method(), "GET");
+        assert_eq!(ListZones.path(), "/zones");
+        assert_eq!(ListZones.body(), None);
+    }
+
+    #[test]
+    fn purge_cache_path_includes_zone_id() {
+        let endpoint = PurgeCache { zone_id: "abc123".to_string(), files: None };
+        assert_eq!(endpoint.method(), "POST");
+        assert_eq!(endpoint.path(), "/zones/abc123/purge_cache");
+    }
+
+    #[test]
+    fn purge_cache_with_no_files_purges_everything() {
+        let endpoint = PurgeCache { zone_id: "abc123".to_string(), files: None };
+        assert_eq!(endpoint.body(), Some(r#"{"purge_everything":true}"#.to_string()));
+    }
+
+    #[test]
+    fn purge_cache_with_an_explicit_empty_file_list_does_not_purge_everything() {
+        let endpoint = PurgeCache { zone_id: "abc123".to_string(), files: Some(vec![]) };
+        assert_eq!(endpoint.body(), Some(r#"{"files":[]}"#.to_string()));
+    }
+
+    #[test]
+    fn purge_cache_with_files_sends_file_list() {
+        let endpoint = PurgeCache { zone_id: "abc123".to_string(), files: Some(vec!["https://example.com/a".to_string()]) };
+        assert_eq!(endpoint.body(), Some(r#"{"files":["https://example.com/a"]}"#.to_string()));
+    }
+}
@@ -0,0 +1,201 @@
+//! An optional client for calling back into Cloudflare's own v4 REST API
+//! (purge cache, read DNS records, manage KV namespaces) from inside a
+//! Worker handler. Requests are issued via the runtime's `fetch`, since
+//! blocking I/O is impossible inside a Worker.
+
+mod client;
+mod endpoints;
+
+pub use client::{ApiClient, ApiResponse, Credentials};
+pub use endpoints::{Endpoint, ListZones, PurgeCache, Zone};
+
+use serde::Serialize;
+
+use crate::request::Request;
+use crate::response::Response;
+use crate::router::Router;
+
+/// Registers this module's routes (zone listing, cache purging) on
+/// `router`, ahead of the catch-all echo route.
+pub(crate) fn register_routes(router: &mut Router) {
+    router.route_async("GET", "/zones", |request, _params| {
+        let request = request.clone();
+        Box::pin(async move { handle_zones(&request).await })
+    });
+    router.route_async("POST", "/zones/:id/purge_cache", |request, params| {
+        let request = request.clone();
+        let zone_id = params.get("id").cloned().unwrap_or_default();
+        Box::pin(async move { handle_purge_cache(&request, zone_id).await })
+    });
+}
+
+/// Builds an [`ApiClient`] from the request's `Authorization` header, or a
+/// 401 [`Response`] if the header is missing. Callers send the header in
+/// the usual `Bearer <token>` form; that prefix is stripped here so
+/// [`ApiClient::auth_headers`] doesn't send `Bearer Bearer <token>` to the
+/// upstream v4 API.
+fn authorize(request: &Request) -> Result<ApiClient, Response> {
+    request
+        .header("Authorization")
+        .map(|token| {
+            let token = match token.get(.."Bearer ".len()) {
+                Some(prefix) if prefix.eq_ignore_ascii_case("Bearer ") => &token["Bearer ".len()..],
+                _ => &token,
+            };
+            ApiClient::new(Credentials::Token(token.to_string()))
+        })
+        .ok_or_else(|| Response::new(401, "missing Authorization header".to_string()))
+}
+
+/// Folds a v4 API envelope into a [`Response`], returning a non-2xx
+/// response (with the API's own errors as the body) when `success` is
+/// `false` rather than silently returning an empty success body.
+fn api_response_to_http<T: Serialize + Default>(response: ApiResponse<T>) -> Response {
+    if !response.success {
+        let body = serde_json::to_string(&response.errors).unwrap_or_else(|_| "[]".to_string());
+        return Response::new(502, body).with_header("Content-Type", "application/json");
+    }
+    let body = serde_json::to_string(&response.result.unwrap_or_default()).unwrap_or_else(|_| "null".to_string());
+    Response::new(200, body).with_header("Content-Type", "application/json")
+}
+
+/// Calls the v4 zones endpoint using the request's `Authorization` header
+/// as the API token, and folds the result into a [`Response`].
+async fn handle_zones(request: &Request) -> Response {
+    let client = match authorize(request) {
+        Ok(client) => client,
+        Err(response) => return response,
+    };
+    match client.send(&ListZones).await {
+        Ok(response) => api_response_to_http(response),
+        Err(err) => Response::new(502, err.as_string().unwrap_or_else(|| "fetch failed".to_string())),
+    }
+}
+
+/// Parses the `files` list to purge from a request body. `None` (no body)
+/// means "purge everything", which [`PurgeCache::body`] only synthesizes
+/// when `files` is `None`; an explicit empty JSON array parses to
+/// `Some(vec![])` and must stay distinct so it purges nothing rather than
+/// everything. A body that's present and fails to parse as a JSON array of
+/// file URLs is a client error and must not be silently treated as either.
+fn parse_purge_files(body: Option<String>) -> Result<Option<Vec<String>>, Response> {
+    match body {
+        None => Ok(None),
+        Some(body) => serde_json::from_str(&body)
+            .map(Some)
+            .map_err(|err| Response::new(400, format!("invalid purge_cache body: {}", err))),
+    }
+}
+
+/// Calls the v4 cache-purge endpoint for `zone_id`, purging the files
+/// named in the request's JSON body, or everything in the zone if the
+/// body is absent.
+async fn handle_purge_cache(request: &Request, zone_id: String) -> Response {
+    let client = match authorize(request) {
+        Ok(client) => client,
+        Err(response) => return response,
+    };
+    let files = match parse_purge_files(request.body()) {
+        Ok(files) => files,
+        Err(response) => return response,
+    };
+    match client.send(&PurgeCache { zone_id, files }).await {
+        Ok(response) => api_response_to_http(response),
+        Err(err) => Response::new(502, err.as_string().unwrap_or_else(|| "fetch failed".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request_with_auth(token: Option<&str>) -> Request {
+        let mut request = Request::new("GET".to_string(), "/zones".to_string(), None);
+        if let Some(token) = token {
+            request.set_header("Authorization".to_string(), token.to_string());
+        }
+        request
+    }
+
+    #[test]
+    fn authorize_rejects_missing_header_with_401() {
+        match authorize(&request_with_auth(None)) {
+            Err(response) => assert_eq!(response.status(), 401),
+            Ok(_) => panic!("expected 401 response"),
+        }
+    }
+
+    #[test]
+    fn authorize_accepts_authorization_header() {
+        assert!(authorize(&request_with_auth(Some("token"))).is_ok());
+    }
+
+    #[test]
+    fn authorize_strips_bearer_prefix_before_sending_upstream() {
+        match authorize(&request_with_auth(Some("Bearer abc123"))) {
+            Ok(client) => assert_eq!(client.auth_headers().get("Authorization"), Some(&"Bearer abc123".to_string())),
+            Err(_) => panic!("expected a client"),
+        }
+    }
+
+    #[test]
+    fn authorize_passes_through_a_bare_token_unchanged() {
+        match authorize(&request_with_auth(Some("abc123"))) {
+            Ok(client) => assert_eq!(client.auth_headers().get("Authorization"), Some(&"Bearer abc123".to_string())),
+            Err(_) => panic!("expected a client"),
+        }
+    }
+
+    #[test]
+    fn api_response_to_http_returns_502_on_failure() {
+        let response: ApiResponse<Vec<Zone>> = ApiResponse {
+            result: None,
+            success: false,
+            errors: vec![client::ApiError { code: 1000, message: "bad token".to_string() }],
+        };
+        let response = api_response_to_http(response);
+        assert_eq!(response.status(), 502);
+        assert!(response.body().contains("bad token"));
+    }
+
+    #[test]
+    fn parse_purge_files_is_none_when_body_absent() {
+        match parse_purge_files(None) {
+            Ok(files) => assert_eq!(files, None),
+            Err(_) => panic!("expected no file list"),
+        }
+    }
+
+    #[test]
+    fn parse_purge_files_parses_a_file_list() {
+        match parse_purge_files(Some(r#"["https://example.com/a"]"#.to_string())) {
+            Ok(files) => assert_eq!(files, Some(vec!["https://example.com/a".to_string()])),
+            Err(_) => panic!("expected a parsed file list"),
+        }
+    }
+
+    #[test]
+    fn parse_purge_files_keeps_an_explicit_empty_array_distinct_from_no_body() {
+        match parse_purge_files(Some("[]".to_string())) {
+            Ok(files) => assert_eq!(files, Some(Vec::new())),
+            Err(_) => panic!("expected an explicit empty file list, not None"),
+        }
+    }
+
+    #[test]
+    fn parse_purge_files_rejects_malformed_body_with_400_instead_of_purging_everything() {
+        match parse_purge_files(Some("not json".to_string())) {
+            Err(response) => assert_eq!(response.status(), 400),
+            Ok(_) => panic!("expected a 400 response, not a fallback to purge_everything"),
+        }
+    }
+
+    #[test]
+    fn api_response_to_http_returns_200_with_result_on_success() {
+        let response: ApiResponse<Vec<Zone>> =
+            ApiResponse { result: Some(vec![Zone { id: "1".to_string(), name: "example.com".to_string() }]), success: true, errors: vec![] };
+        let response = api_response_to_http(response);
+        assert_eq!(response.status(), 200);
+        assert!(response.body().contains("example.com"));
+    }
+}
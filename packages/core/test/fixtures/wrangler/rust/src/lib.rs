@@ -1,8 +1,54 @@
 extern crate wasm_bindgen;
 
+#[cfg(feature = "cloudflare")]
+mod cloudflare;
+mod request;
+mod response;
+mod router;
+
 use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::future_to_promise;
+
+#[cfg(feature = "cloudflare")]
+pub use cloudflare::{ApiClient, ApiResponse, Credentials, Endpoint, ListZones, PurgeCache, Zone};
+pub use request::Request;
+pub use response::Response;
+use response::content_type_for;
+pub use router::{Params, PathPattern, Router};
 
 #[wasm_bindgen]
 pub fn respond(url: String) -> String {
     format!("rust:{}", url)
 }
+
+/// Builds the default router from any `cloudflare`-feature routes, plus a
+/// fallback to the original echo behaviour for any path none of them
+/// match. The fallback only ever runs once every registered route has
+/// failed to match, so a `cloudflare` route's method is still enforced
+/// with a real 405 rather than being shadowed by the echo.
+fn router() -> Router {
+    let mut router = Router::new();
+    #[cfg(feature = "cloudflare")]
+    cloudflare::register_routes(&mut router);
+    router.fallback(|request, _params| {
+        let body = respond(request.url());
+        Response::new(200, body).with_header("Content-Type", content_type_for(&request.path()))
+    });
+    router
+}
+
+/// Async entry point for handlers that need to `await` a subrequest, KV
+/// read, or timer before producing a body. Takes the full incoming
+/// [`Request`] (method, headers, body) rather than just its URL, so
+/// handlers can implement real routing instead of string-only echoing.
+/// Dispatches through the [`Router`] built by [`router`], so a `cloudflare`
+/// route's method is enforced the same way any other route's is, and a
+/// path none of them match falls back to the original echo behaviour.
+/// Resolves the returned `Promise` with a [`Response`].
+#[wasm_bindgen]
+pub fn respond_async(request: Request) -> js_sys::Promise {
+    future_to_promise(async move {
+        let response = router().dispatch_async(&request).await;
+        Ok(JsValue::from(response))
+    })
+}
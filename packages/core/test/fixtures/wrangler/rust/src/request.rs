@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use wasm_bindgen::prelude::*;
+
+/// The incoming HTTP request: method, URL, headers, and an optional body.
+///
+/// Carries everything `respond`'s bare `url: String` threw away, so a
+/// handler can branch on method, read headers, or consume a POST body
+/// instead of only ever seeing the URL.
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Request {
+    method: String,
+    url: String,
+    headers: HashMap<String, String>,
+    body: Option<String>,
+}
+
+#[wasm_bindgen]
+impl Request {
+    #[wasm_bindgen(constructor)]
+    pub fn new(method: String, url: String, body: Option<String>) -> Request {
+        Request { method, url, headers: HashMap::new(), body }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn method(&self) -> String {
+        self.method.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn body(&self) -> Option<String> {
+        self.body.clone()
+    }
+
+    /// Looks up a request header by name, case-insensitively (HTTP header
+    /// names are case-insensitive, and hosts vary in how they normalize
+    /// them before calling [`Request::set_header`]).
+    pub fn header(&self, name: &str) -> Option<String> {
+        self.headers.get(&name.to_ascii_lowercase()).cloned()
+    }
+
+    /// Sets a request header by name; used by hosts that construct a
+    /// `Request` from `web_sys::Request` headers before dispatching it.
+    /// Names are stored lower-cased so lookups in [`Request::header`] are
+    /// case-insensitive.
+    pub fn set_header(&mut self, name: String, value: String) {
+        self.headers.insert(name.to_ascii_lowercase(), value);
+    }
+
+    /// The path component of `url`, with any `scheme://host` prefix and
+    /// query string or fragment stripped. A Workers `fetch` handler's
+    /// `Request.url` is typically an absolute URL, but route matching and
+    /// content-type inference only care about the path.
+    pub fn path(&self) -> String {
+        path_of(&self.url).to_string()
+    }
+}
+
+pub(crate) fn path_of(url: &str) -> &str {
+    // Only treat `://` as a scheme separator if it appears before the first
+    // `/`; otherwise a relative path whose query string happens to contain
+    // `://` (e.g. `/redirect?to=https://evil.com`) would be misread as an
+    // absolute URL and its real path discarded.
+    let scheme_end = url.find("://").filter(|&i| url[..i].find('/').is_none());
+    let without_scheme = match scheme_end {
+        Some(scheme_end) => {
+            let after_scheme = &url[scheme_end + 3..];
+            match after_scheme.find('/') {
+                Some(path_start) => &after_scheme[path_start..],
+                None => "/",
+            }
+        }
+        None => url,
+    };
+    match without_scheme.find(['?', '#']) {
+        Some(end) => &without_scheme[..end],
+        None => without_scheme,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_scheme_and_host() {
+        assert_eq!(path_of("https://example.com/users/1"), "/users/1");
+    }
+
+    #[test]
+    fn strips_query_string() {
+        assert_eq!(path_of("/search?q=rust"), "/search");
+    }
+
+    #[test]
+    fn strips_fragment() {
+        assert_eq!(path_of("/docs#installation"), "/docs");
+    }
+
+    #[test]
+    fn strips_query_string_with_absolute_url() {
+        assert_eq!(path_of("https://example.com/search?q=rust#top"), "/search");
+    }
+
+    #[test]
+    fn absolute_url_with_no_path_is_root() {
+        assert_eq!(path_of("https://example.com"), "/");
+    }
+
+    #[test]
+    fn relative_path_with_no_extension_is_unchanged() {
+        assert_eq!(path_of("/users/1"), "/users/1");
+    }
+
+    #[test]
+    fn relative_path_with_scheme_like_text_in_query_is_unchanged() {
+        assert_eq!(path_of("/search?url=http://example.com"), "/search");
+        assert_eq!(path_of("/redirect?to=https://evil.com/path"), "/redirect");
+    }
+
+    #[test]
+    fn header_lookup_is_case_insensitive() {
+        let mut request = Request::new("GET".to_string(), "/".to_string(), None);
+        request.set_header("Authorization".to_string(), "Bearer token".to_string());
+        assert_eq!(request.header("authorization"), Some("Bearer token".to_string()));
+        assert_eq!(request.header("AUTHORIZATION"), Some("Bearer token".to_string()));
+    }
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use wasm_bindgen::prelude::*;
+
+/// A structured HTTP response: status code, headers, and body.
+///
+/// Replaces the bare `String` body so a handler can set a status code and
+/// headers (e.g. `Content-Type`) instead of every response being an
+/// untyped string.
+#[wasm_bindgen]
+pub struct Response {
+    status: u16,
+    headers: HashMap<String, String>,
+    body: String,
+}
+
+#[wasm_bindgen]
+impl Response {
+    #[wasm_bindgen(getter)]
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn body(&self) -> String {
+        self.body.clone()
+    }
+
+    /// Looks up a response header by name.
+    pub fn header(&self, name: &str) -> Option<String> {
+        self.headers.get(name).cloned()
+    }
+}
+
+impl Response {
+    pub fn new(status: u16, body: String) -> Self {
+        Response { status, headers: HashMap::new(), body }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.insert(name.to_string(), value.to_string());
+        self
+    }
+}
+
+/// Infers a `Content-Type` from a request path's extension, the way a
+/// static file server would.
+pub fn content_type_for(path: &str) -> &'static str {
+    match path.rsplit('.').next() {
+        Some("html") => "text/html",
+        Some("js") => "application/javascript",
+        Some("json") => "application/json",
+        Some("css") => "text/css",
+        Some("svg") => "image/svg+xml",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infers_known_extensions() {
+        assert_eq!(content_type_for("/index.html"), "text/html");
+        assert_eq!(content_type_for("/app.js"), "application/javascript");
+        assert_eq!(content_type_for("/data.json"), "application/json");
+        assert_eq!(content_type_for("/styles.css"), "text/css");
+        assert_eq!(content_type_for("/logo.svg"), "image/svg+xml");
+    }
+
+    #[test]
+    fn defaults_to_octet_stream_for_unknown_or_missing_extension() {
+        assert_eq!(content_type_for("/archive.zip"), "application/octet-stream");
+        assert_eq!(content_type_for("/users/1"), "application/octet-stream");
+    }
+}
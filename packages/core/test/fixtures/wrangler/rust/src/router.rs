@@ -0,0 +1,325 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+
+use crate::request::Request;
+use crate::response::Response;
+
+/// An HTTP method a [`Router`] can match on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+    Head,
+}
+
+impl Method {
+    fn parse(method: &str) -> Option<Method> {
+        match method.to_ascii_uppercase().as_str() {
+            "GET" => Some(Method::Get),
+            "POST" => Some(Method::Post),
+            "PUT" => Some(Method::Put),
+            "PATCH" => Some(Method::Patch),
+            "DELETE" => Some(Method::Delete),
+            "HEAD" => Some(Method::Head),
+            _ => None,
+        }
+    }
+}
+
+/// A single segment of a compiled [`PathPattern`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// A literal path segment, e.g. `users`.
+    Literal(String),
+    /// A `:name` capture.
+    Param(String),
+    /// A `*` catch-all; must be the last segment.
+    Wildcard,
+}
+
+/// A route template such as `/users/:id` compiled into matchable segments.
+#[derive(Debug, Clone)]
+pub struct PathPattern {
+    segments: Vec<Segment>,
+}
+
+impl PathPattern {
+    pub fn compile(template: &str) -> PathPattern {
+        let segments: Vec<Segment> = template
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                if segment == "*" {
+                    Segment::Wildcard
+                } else if let Some(name) = segment.strip_prefix(':') {
+                    Segment::Param(name.to_string())
+                } else {
+                    Segment::Literal(segment.to_string())
+                }
+            })
+            .collect();
+
+        if let Some(index) = segments.iter().position(|segment| *segment == Segment::Wildcard) {
+            assert_eq!(
+                index,
+                segments.len() - 1,
+                "`*` must be the last segment in a route pattern: {}",
+                template
+            );
+        }
+
+        PathPattern { segments }
+    }
+
+    /// Matches `path` segment-by-segment, returning captured params on
+    /// success.
+    fn matches(&self, path: &[&str]) -> Option<HashMap<String, String>> {
+        let mut params = HashMap::new();
+        let mut path_iter = path.iter();
+
+        for segment in &self.segments {
+            match segment {
+                Segment::Wildcard => {
+                    return Some(params);
+                }
+                Segment::Literal(literal) => {
+                    if path_iter.next() != Some(&literal.as_str()) {
+                        return None;
+                    }
+                }
+                Segment::Param(name) => {
+                    let value = path_iter.next()?;
+                    params.insert(name.clone(), value.to_string());
+                }
+            }
+        }
+
+        if path_iter.next().is_some() {
+            return None;
+        }
+
+        Some(params)
+    }
+}
+
+/// Params captured from a matched [`PathPattern`], keyed by name.
+pub type Params = HashMap<String, String>;
+
+/// A handler invoked with the matched request and its captured params.
+pub type Handler = Box<dyn Fn(&Request, &Params) -> Response>;
+
+/// An async handler invoked with the matched request and its captured
+/// params, for routes that need to `await` a subrequest (e.g. a call into
+/// the [`cloudflare`](crate::cloudflare) client) before producing a
+/// [`Response`].
+pub type AsyncHandler = Box<dyn Fn(&Request, &Params) -> Pin<Box<dyn Future<Output = Response>>>>;
+
+enum RouteHandler {
+    Sync(Handler),
+    Async(AsyncHandler),
+}
+
+enum Matched<'a> {
+    Handler(&'a RouteHandler, Params),
+    WrongMethod,
+    NotFound,
+}
+
+/// A small URL router: registers `(Method, PathPattern, Handler)` entries
+/// and dispatches a [`Request`] to the first one that matches, turning the
+/// echo-only `respond` function into a usable Workers micro-framework.
+#[derive(Default)]
+pub struct Router {
+    routes: Vec<(Method, PathPattern, RouteHandler)>,
+    fallback: Option<RouteHandler>,
+}
+
+impl Router {
+    pub fn new() -> Router {
+        Router { routes: Vec::new(), fallback: None }
+    }
+
+    /// Registers a handler invoked when no route's path matches, in place
+    /// of the default 404. Unlike a route, it runs for any method and any
+    /// path, so it's only ever tried once every registered route has
+    /// already failed to match — a path that matches some route's pattern
+    /// but not its method still gets a real 405, not the fallback.
+    pub fn fallback(&mut self, handler: impl Fn(&Request, &Params) -> Response + 'static) -> &mut Self {
+        self.fallback = Some(RouteHandler::Sync(Box::new(handler)));
+        self
+    }
+
+    /// Registers a route, e.g. `router.route("GET", "/users/:id", handler)`.
+    pub fn route(
+        &mut self,
+        method: &str,
+        pattern: &str,
+        handler: impl Fn(&Request, &Params) -> Response + 'static,
+    ) -> &mut Self {
+        self.push(method, pattern, RouteHandler::Sync(Box::new(handler)));
+        self
+    }
+
+    /// Registers an async route, e.g. a handler that calls into the
+    /// `cloudflare` client before responding. Must be dispatched with
+    /// [`Router::dispatch_async`].
+    pub fn route_async(
+        &mut self,
+        method: &str,
+        pattern: &str,
+        handler: impl Fn(&Request, &Params) -> Pin<Box<dyn Future<Output = Response>>> + 'static,
+    ) -> &mut Self {
+        self.push(method, pattern, RouteHandler::Async(Box::new(handler)));
+        self
+    }
+
+    fn push(&mut self, method: &str, pattern: &str, handler: RouteHandler) {
+        let method = Method::parse(method).unwrap_or_else(|| panic!("unknown method: {}", method));
+        self.routes.push((method, PathPattern::compile(pattern), handler));
+    }
+
+    /// Matches `request` against the registered routes, without invoking
+    /// the handler. Used by both [`Router::dispatch`] and
+    /// [`Router::dispatch_async`].
+    fn find(&self, request: &Request) -> Matched<'_> {
+        let path = request.path();
+        let path: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let method = Method::parse(&request.method());
+
+        let mut path_matched = false;
+        for (route_method, pattern, handler) in &self.routes {
+            if let Some(params) = pattern.matches(&path) {
+                path_matched = true;
+                if Some(*route_method) == method {
+                    return Matched::Handler(handler, params);
+                }
+            }
+        }
+
+        if path_matched {
+            Matched::WrongMethod
+        } else {
+            Matched::NotFound
+        }
+    }
+
+    /// Dispatches `request` to the first matching route. Returns a 404
+    /// when no pattern matches the path and no [`Router::fallback`] is
+    /// registered, and a 405 when the path matches but not for the
+    /// request's method.
+    ///
+    /// Panics if the matched route was registered with
+    /// [`Router::route_async`]; use [`Router::dispatch_async`] for routers
+    /// that may contain async routes.
+    pub fn dispatch(&self, request: &Request) -> Response {
+        match self.find(request) {
+            Matched::Handler(RouteHandler::Sync(handler), params) => handler(request, &params),
+            Matched::Handler(RouteHandler::Async(_), _) => {
+                panic!("route matched an async handler; use Router::dispatch_async instead")
+            }
+            Matched::WrongMethod => Response::new(405, "Method Not Allowed".to_string()),
+            Matched::NotFound => self.dispatch_fallback(request),
+        }
+    }
+
+    /// Like [`Router::dispatch`], but also supports routes registered with
+    /// [`Router::route_async`].
+    pub async fn dispatch_async(&self, request: &Request) -> Response {
+        match self.find(request) {
+            Matched::Handler(RouteHandler::Sync(handler), params) => handler(request, &params),
+            Matched::Handler(RouteHandler::Async(handler), params) => handler(request, &params).await,
+            Matched::WrongMethod => Response::new(405, "Method Not Allowed".to_string()),
+            Matched::NotFound => self.dispatch_fallback(request),
+        }
+    }
+
+    fn dispatch_fallback(&self, request: &Request) -> Response {
+        match &self.fallback {
+            Some(RouteHandler::Sync(handler)) => handler(request, &Params::new()),
+            Some(RouteHandler::Async(_)) => unreachable!("Router::fallback only registers sync handlers"),
+            None => Response::new(404, "Not Found".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_named_params() {
+        let pattern = PathPattern::compile("/users/:id");
+        let params = pattern.matches(&["users", "42"]).expect("should match");
+        assert_eq!(params.get("id"), Some(&"42".to_string()));
+    }
+
+    #[test]
+    fn wildcard_matches_any_remaining_segments() {
+        let pattern = PathPattern::compile("/files/*");
+        assert!(pattern.matches(&["files", "a", "b", "c"]).is_some());
+        assert!(pattern.matches(&["files"]).is_some());
+        assert!(pattern.matches(&["other"]).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "`*` must be the last segment")]
+    fn wildcard_must_be_last_segment() {
+        PathPattern::compile("/files/*/meta");
+    }
+
+    #[test]
+    fn literal_mismatch_does_not_match() {
+        let pattern = PathPattern::compile("/users/:id");
+        assert!(pattern.matches(&["posts", "42"]).is_none());
+    }
+
+    #[test]
+    fn extra_trailing_segment_does_not_match() {
+        let pattern = PathPattern::compile("/users/:id");
+        assert!(pattern.matches(&["users", "42", "posts"]).is_none());
+    }
+
+    fn request(method: &str, url: &str) -> Request {
+        Request::new(method.to_string(), url.to_string(), None)
+    }
+
+    #[test]
+    fn dispatch_returns_404_when_no_path_matches() {
+        let mut router = Router::new();
+        router.route("GET", "/users/:id", |_request, _params| Response::new(200, "ok".to_string()));
+        let response = router.dispatch(&request("GET", "/posts/1"));
+        assert_eq!(response.status(), 404);
+    }
+
+    #[test]
+    fn dispatch_returns_405_when_path_matches_but_method_does_not() {
+        let mut router = Router::new();
+        router.route("GET", "/users/:id", |_request, _params| Response::new(200, "ok".to_string()));
+        let response = router.dispatch(&request("POST", "/users/1"));
+        assert_eq!(response.status(), 405);
+    }
+
+    #[test]
+    fn dispatch_invokes_matching_handler_with_params() {
+        let mut router = Router::new();
+        router.route("GET", "/users/:id", |_request, params| {
+            Response::new(200, params.get("id").cloned().unwrap_or_default())
+        });
+        let response = router.dispatch(&request("GET", "/users/7"));
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.body(), "7");
+    }
+
+    #[test]
+    fn dispatch_tries_routes_in_registration_order() {
+        let mut router = Router::new();
+        router.route("GET", "/zones", |_request, _params| Response::new(200, "specific".to_string()));
+        router.route("GET", "/*", |_request, _params| Response::new(200, "catch-all".to_string()));
+        let response = router.dispatch(&request("GET", "/zones"));
+        assert_eq!(response.body(), "specific");
+    }
+}